@@ -80,6 +80,11 @@ async fn main() -> std::io::Result<()> {
         .await
         .failed("Invalid configuration");
 
+    // Fixed delay before exiting after shutdown is signalled
+    let grace_period = config
+        .property_or_static::<Duration>("server.shutdown.grace-period", "1s")
+        .failed("Invalid configuration");
+
     // Init servers
     let (delivery_tx, delivery_rx) = mpsc::channel(IPC_CHANNEL_BUFFER);
     let smtp = SMTP::init(&config, &servers, &stores, &directory, delivery_tx)
@@ -137,7 +142,7 @@ async fn main() -> std::io::Result<()> {
     let _ = shutdown_tx.send(true);
 
     // Wait for services to finish
-    tokio::time::sleep(Duration::from_secs(1)).await;
+    tokio::time::sleep(grace_period).await;
 
     Ok(())
 }